@@ -2,7 +2,7 @@
 //!
 //! This driver was built using [`embedded-hal`] traits.
 //!
-//! [`embedded-hal`]: https://docs.rs/embedded-hal/~0.1
+//! [`embedded-hal`]: https://docs.rs/embedded-hal/~1.0
 //!
 //! # Examples
 //!
@@ -10,13 +10,11 @@
 
 #![no_std]
 
-use core::mem;
-
 #[cfg(feature = "defmt")]
 use defmt::{debug, error, trace, Format};
 
 #[cfg(feature = "blocking")]
-use embedded_hal::blocking::i2c::{Write, WriteRead};
+use embedded_hal::i2c::I2c;
 
 #[cfg(all(feature = "blocking", feature = "async"))]
 compile_error!("feature \"blocking\" and feature \"async\" cannot be enabled at the same time");
@@ -24,10 +22,39 @@ compile_error!("feature \"blocking\" and feature \"async\" cannot be enabled at
 #[cfg(feature = "async")]
 use embedded_hal_async::i2c::I2c;
 
-use embedded_hal::i2c::Error;
-
 use cast::{i32, u16, u32};
 
+#[cfg(feature = "async")]
+struct YieldNow(bool);
+
+#[cfg(feature = "async")]
+impl core::future::Future for YieldNow {
+    type Output = ();
+
+    fn poll(
+        mut self: core::pin::Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+    ) -> core::task::Poll<()> {
+        if self.0 {
+            core::task::Poll::Ready(())
+        } else {
+            self.0 = true;
+            cx.waker().wake_by_ref();
+            core::task::Poll::Pending
+        }
+    }
+}
+
+/// Yields once to the executor, letting other tasks run while a triggered conversion completes.
+#[cfg(feature = "async")]
+async fn yield_now() {
+    YieldNow(false).await
+}
+
+/// Blocking builds simply spin; there is no executor to yield to.
+#[cfg(feature = "blocking")]
+fn yield_now() {}
+
 #[allow(dead_code)]
 #[allow(non_camel_case_types)]
 #[derive(Copy, Clone)]
@@ -89,10 +116,44 @@ pub enum Averaging {
 }
 
 impl Averaging {
+    const ALL: [Averaging; 8] = [
+        Averaging::AVG1,
+        Averaging::AVG4,
+        Averaging::AVG16,
+        Averaging::AVG64,
+        Averaging::AVG128,
+        Averaging::AVG256,
+        Averaging::AVG512,
+        Averaging::AVG1024,
+    ];
+
     #[inline(always)]
     pub fn bits(self) -> u16 {
         self as u16
     }
+
+    /// The number of samples averaged together for this setting.
+    #[inline(always)]
+    pub fn count(self) -> u32 {
+        match self {
+            Averaging::AVG1 => 1,
+            Averaging::AVG4 => 4,
+            Averaging::AVG16 => 16,
+            Averaging::AVG64 => 64,
+            Averaging::AVG128 => 128,
+            Averaging::AVG256 => 256,
+            Averaging::AVG512 => 512,
+            Averaging::AVG1024 => 1024,
+        }
+    }
+
+    fn from_bits(bits: u16) -> Averaging {
+        let masked = bits & Averaging::AVG1024.bits();
+        Self::ALL
+            .into_iter()
+            .find(|a| a.bits() == masked)
+            .unwrap_or(Averaging::AVG1)
+    }
 }
 
 #[allow(dead_code)]
@@ -120,10 +181,44 @@ pub enum BVConvTime {
 }
 
 impl BVConvTime {
+    const ALL: [BVConvTime; 8] = [
+        BVConvTime::US140,
+        BVConvTime::US204,
+        BVConvTime::US332,
+        BVConvTime::US588,
+        BVConvTime::MS1_1,
+        BVConvTime::MS2_116,
+        BVConvTime::MS4_156,
+        BVConvTime::MS8_244,
+    ];
+
     #[inline(always)]
     pub fn bits(self) -> u16 {
         self as u16
     }
+
+    /// The bus voltage conversion time in microseconds.
+    #[inline(always)]
+    pub fn us(self) -> u32 {
+        match self {
+            BVConvTime::US140 => 140,
+            BVConvTime::US204 => 204,
+            BVConvTime::US332 => 332,
+            BVConvTime::US588 => 588,
+            BVConvTime::MS1_1 => 1_100,
+            BVConvTime::MS2_116 => 2_116,
+            BVConvTime::MS4_156 => 4_156,
+            BVConvTime::MS8_244 => 8_244,
+        }
+    }
+
+    fn from_bits(bits: u16) -> BVConvTime {
+        let masked = bits & BVConvTime::MS8_244.bits();
+        Self::ALL
+            .into_iter()
+            .find(|b| b.bits() == masked)
+            .unwrap_or(BVConvTime::US140)
+    }
 }
 
 #[allow(dead_code)]
@@ -151,10 +246,44 @@ pub enum SCConvTime {
 }
 
 impl SCConvTime {
+    const ALL: [SCConvTime; 8] = [
+        SCConvTime::US140,
+        SCConvTime::US204,
+        SCConvTime::US332,
+        SCConvTime::US588,
+        SCConvTime::MS1_1,
+        SCConvTime::MS2_116,
+        SCConvTime::MS4_156,
+        SCConvTime::MS8_244,
+    ];
+
     #[inline(always)]
     pub fn bits(self) -> u16 {
         self as u16
     }
+
+    /// The shunt current conversion time in microseconds.
+    #[inline(always)]
+    pub fn us(self) -> u32 {
+        match self {
+            SCConvTime::US140 => 140,
+            SCConvTime::US204 => 204,
+            SCConvTime::US332 => 332,
+            SCConvTime::US588 => 588,
+            SCConvTime::MS1_1 => 1_100,
+            SCConvTime::MS2_116 => 2_116,
+            SCConvTime::MS4_156 => 4_156,
+            SCConvTime::MS8_244 => 8_244,
+        }
+    }
+
+    fn from_bits(bits: u16) -> SCConvTime {
+        let masked = bits & SCConvTime::MS8_244.bits();
+        Self::ALL
+            .into_iter()
+            .find(|s| s.bits() == masked)
+            .unwrap_or(SCConvTime::US140)
+    }
 }
 
 #[allow(dead_code)]
@@ -186,6 +315,105 @@ impl OperMode {
     }
 }
 
+#[allow(dead_code)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(Format))]
+/// Alert Function
+/// Selects which function is monitored by the ALERT pin. Only one function may be active at a
+/// time; writing a new one replaces whichever was previously selected.
+pub enum AlertFunction {
+    // Over-Current Limit
+    OCL = 0b1000_0000_0000_0000,
+    // Under-Current Limit
+    UCL = 0b0100_0000_0000_0000,
+    // Bus-Over-Voltage
+    BOL = 0b0010_0000_0000_0000,
+    // Bus-Under-Voltage
+    BUL = 0b0001_0000_0000_0000,
+    // Over-Power Limit
+    POL = 0b0000_1000_0000_0000,
+    // Conversion Ready
+    CNVR = 0b0000_0100_0000_0000,
+}
+
+impl AlertFunction {
+    #[inline(always)]
+    pub fn bits(self) -> u16 {
+        self as u16
+    }
+}
+
+#[allow(dead_code)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(Format))]
+/// Alert Polarity
+/// Selects the output polarity of the ALERT pin.
+pub enum AlertPolarity {
+    // Active-low, open-collector (default)
+    Normal = 0b0000_0000_0000_0000,
+    // Active-high
+    Inverted = 0b0000_0000_0000_0010,
+}
+
+impl AlertPolarity {
+    #[inline(always)]
+    pub fn bits(self) -> u16 {
+        self as u16
+    }
+}
+
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(Format))]
+/// The read-only status bits reported in bits 4-2 of MASK_ENABLE.
+pub struct AlertFlags {
+    /// Set once the selected alert function's limit has been exceeded.
+    pub alert_function_flag: bool,
+    /// Set once a conversion has completed. Reading MASK_ENABLE (or any value register) clears it.
+    pub conversion_ready_flag: bool,
+    /// Set when an internal calculation has overflowed, signalling an out-of-range result.
+    pub math_overflow_flag: bool,
+}
+
+#[allow(dead_code)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(Format))]
+/// Address Pin Strap
+/// Selects which rail or bus line an address pin (A0/A1) is tied to.
+pub enum Pin {
+    Gnd = 0,
+    Vs = 1,
+    Sda = 2,
+    Scl = 3,
+}
+
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(Format))]
+/// A validated 7-bit I2C address for an INA260, derived from how its A1/A0 pins are strapped.
+pub struct Address(u8);
+
+impl Address {
+    /// The power-on default address, with both A1 and A0 tied to GND.
+    pub const DEFAULT: Address = Address(0x40);
+
+    /// Computes the address set by tying A1 and A0 to the given pins, following the documented
+    /// strap table (0x40 with both pins at GND, up to 0x4F with both at SCL).
+    #[inline(always)]
+    pub fn from_pins(a1: Pin, a0: Pin) -> Address {
+        Address(0x40 + (a1 as u8) * 4 + (a0 as u8))
+    }
+
+    #[inline(always)]
+    pub fn addr(self) -> u8 {
+        self.0
+    }
+}
+
+impl From<Address> for u8 {
+    fn from(a: Address) -> u8 {
+        a.0
+    }
+}
+
 #[maybe_async_cfg::maybe(sync(feature = "blocking", keep_self), async(feature = "async"))]
 pub struct INA260<I2C> {
     i2c: I2C,
@@ -193,109 +421,216 @@ pub struct INA260<I2C> {
     state: u16,
 }
 
-#[maybe_async_cfg::maybe(
-    sync(feature = "blocking", keep_self),
-    async(
-        feature = "async",
-        idents(Write(async = "I2c"), WriteRead(async = "I2c"))
-    )
-)]
+#[maybe_async_cfg::maybe(sync(feature = "blocking", keep_self), async(feature = "async"))]
 impl<I2C, E> INA260<I2C>
 where
-    I2C: WriteRead<Error = E> + Write<Error = E>,
+    I2C: I2c<Error = E>,
 {
     /// Add a new driver for a INA260 chip found on the I2C bus at the specified address
     #[inline(always)]
     pub async fn new_with_address(i2c: I2C, address: u8) -> Result<Self, E> {
+        // `state` is a placeholder until the readback below fills it in; `write_reg`/`read_reg`
+        // never consult it.
         let mut ina260 = Self {
             i2c,
             address,
-            state: OperMode::SCBVC.bits()
-                | Averaging::AVG1.bits()
-                | SCConvTime::MS1_1.bits()
-                | BVConvTime::MS1_1.bits(),
+            state: 0,
         };
         ina260.write_reg(Register::CONFIG, 0x8000).await?;
+        ina260.state = ina260.read_reg(Register::CONFIG).await?;
         Ok(ina260)
     }
 
     #[inline(always)]
     pub async fn new(i2c: I2C) -> Result<Self, E> {
-        Self::new_with_address(i2c, 0x40).await
+        Self::new_with_address(i2c, Address::DEFAULT.addr()).await
+    }
+
+    /// Add a new driver for an INA260 chip whose A1/A0 pins are strapped to `a1`/`a0`, covering
+    /// the full 0x40-0x4F address range without hand-computing the address.
+    #[inline(always)]
+    pub async fn new_with_pins(i2c: I2C, a1: Pin, a0: Pin) -> Result<Self, E> {
+        Self::new_with_address(i2c, Address::from_pins(a1, a0).addr()).await
+    }
+
+    /// Performs a soft reset (CONFIG bit 15) and reloads the cached configuration from the
+    /// device, restoring all settings to their power-on defaults.
+    #[inline(always)]
+    pub async fn reset(&mut self) -> Result<(), E> {
+        self.write_reg(Register::CONFIG, 0x8000).await?;
+        self.state = self.read_reg(Register::CONFIG).await?;
+        Ok(())
     }
 
-    /// Change the averaging mode of the INA260
+    /// Delivers the manufacturer id, which should read back as 0x5449 ("TI") for a genuine
+    /// INA260.
     #[inline(always)]
-    pub async fn set_averaging_mode(&mut self, i2c: &mut I2C, a: Averaging) -> Result<(), E> {
+    pub async fn manufacturer_id(&mut self) -> Result<u16, E> {
+        self.read_reg(Register::MANUFACTURER_ID).await
+    }
+
+    /// Delivers the CONFIG register as currently held by the device, re-syncing `self.state` in
+    /// case it has drifted.
+    #[inline(always)]
+    pub async fn config(&mut self) -> Result<u16, E> {
+        let config = self.read_reg(Register::CONFIG).await?;
+        self.state = config;
+        Ok(config)
+    }
+
+    /// Change the averaging mode of the INA260. CONFIG is then read back into `self.state`, in
+    /// case it had drifted; the value read is not compared with the one written.
+    #[inline(always)]
+    pub async fn set_averaging_mode(&mut self, a: Averaging) -> Result<(), E> {
         let bits = a.bits();
         let state = (self.state & !Averaging::AVG1024.bits()) | bits;
         self.write_reg(Register::CONFIG, state).await?;
-        self.state = state;
+        self.config().await?;
         Ok(())
     }
 
     /// Change the operating mode of the INA260. Please note that if you change to Triggered mode,
     /// you'll have to call this method again each time you would like to get a new sample.
+    /// CONFIG is then read back into `self.state`, in case it had drifted; the value read is not
+    /// compared with the one written.
     #[inline(always)]
-    pub async fn set_operating_mode(&mut self, i2c: &mut I2C, o: OperMode) -> Result<(), E> {
+    pub async fn set_operating_mode(&mut self, o: OperMode) -> Result<(), E> {
         let bits = o.bits();
         let state = (self.state & !OperMode::SCBVC.bits()) | bits;
         self.write_reg(Register::CONFIG, state).await?;
-        self.state = state;
+        self.config().await?;
         Ok(())
     }
 
-    /// Change the shut current conversion time
+    /// Change the shut current conversion time. CONFIG is then read back into `self.state`, in
+    /// case it had drifted; the value read is not compared with the one written.
     #[inline(always)]
-    pub async fn set_scconvtime_mode(&mut self, i2c: &mut I2C, s: SCConvTime) -> Result<(), E> {
+    pub async fn set_scconvtime_mode(&mut self, s: SCConvTime) -> Result<(), E> {
         let bits = s.bits();
         let state = (self.state & !SCConvTime::MS8_244.bits()) | bits;
         self.write_reg(Register::CONFIG, state).await?;
-        self.state = state;
+        self.config().await?;
         Ok(())
     }
 
-    /// Change the bus voltage conversion time
+    /// Change the bus voltage conversion time. CONFIG is then read back into `self.state`, in
+    /// case it had drifted; the value read is not compared with the one written.
     #[inline(always)]
-    pub async fn set_bvconvtime_mode(&mut self, i2c: &mut I2C, b: BVConvTime) -> Result<(), E> {
+    pub async fn set_bvconvtime_mode(&mut self, b: BVConvTime) -> Result<(), E> {
         let bits = b.bits();
         let state = (self.state & !BVConvTime::MS8_244.bits()) | bits;
         self.write_reg(Register::CONFIG, state).await?;
+        self.config().await?;
+        Ok(())
+    }
+
+    /// The effective sampling interval in microseconds for the cached configuration: the
+    /// averaging count times the sum of the conversion times for whichever channels the current
+    /// operating mode enables.
+    #[inline(always)]
+    pub fn sample_interval_us(&self) -> u32 {
+        let shunt_us = if self.state & OperMode::SCT.bits() != 0 {
+            SCConvTime::from_bits(self.state).us()
+        } else {
+            0
+        };
+        let bus_us = if self.state & OperMode::BVT.bits() != 0 {
+            BVConvTime::from_bits(self.state).us()
+        } else {
+            0
+        };
+        Averaging::from_bits(self.state).count() * (shunt_us + bus_us)
+    }
+
+    /// Picks the averaging/conversion-time combination whose `sample_interval_us` is closest to
+    /// `target_us`, given the channels enabled by the current operating mode, and writes it to
+    /// CONFIG.
+    pub async fn set_sample_interval_us(&mut self, target_us: u32) -> Result<(), E> {
+        let shunt_enabled = self.state & OperMode::SCT.bits() != 0;
+        let bus_enabled = self.state & OperMode::BVT.bits() != 0;
+
+        let mut best = (Averaging::AVG1, SCConvTime::US140, BVConvTime::US140);
+        let mut best_diff = u32::MAX;
+        for avg in Averaging::ALL {
+            for sc in SCConvTime::ALL {
+                for bv in BVConvTime::ALL {
+                    let shunt_us = if shunt_enabled { sc.us() } else { 0 };
+                    let bus_us = if bus_enabled { bv.us() } else { 0 };
+                    let diff = (avg.count() * (shunt_us + bus_us)).abs_diff(target_us);
+                    if diff < best_diff {
+                        best_diff = diff;
+                        best = (avg, sc, bv);
+                    }
+                }
+            }
+        }
+
+        let (avg, sc, bv) = best;
+        let state = (self.state
+            & !Averaging::AVG1024.bits()
+            & !SCConvTime::MS8_244.bits()
+            & !BVConvTime::MS8_244.bits())
+            | avg.bits()
+            | sc.bits()
+            | bv.bits();
+        self.write_reg(Register::CONFIG, state).await?;
         self.state = state;
         Ok(())
     }
 
+    /// Configures the ALERT pin to assert on `function`, with the given output polarity and
+    /// latch behavior. Selecting a new function replaces whichever one was previously active.
+    #[inline(always)]
+    pub async fn set_alert(
+        &mut self,
+        function: AlertFunction,
+        polarity: AlertPolarity,
+        latch: bool,
+    ) -> Result<(), E> {
+        let mut value = function.bits() | polarity.bits();
+        if latch {
+            value |= 0b0000_0000_0000_0001;
+        }
+        self.write_reg(Register::MASK_ENABLE, value).await
+    }
+
+    /// Sets the threshold compared against the currently selected alert function. The value is
+    /// in that function's native units: 1.25 mV/bit for voltage functions, 1.25 mA/bit for
+    /// current functions, and 10 mW/bit for the power function.
+    #[inline(always)]
+    pub async fn set_alert_limit(&mut self, raw: u16) -> Result<(), E> {
+        self.write_reg(Register::ALERT_LIMIT, raw).await
+    }
+
+    /// Reads and decodes the read-only status flags from MASK_ENABLE. Note that this clears the
+    /// Conversion-Ready Flag as a side effect.
+    #[inline(always)]
+    pub async fn read_alert_flags(&mut self) -> Result<AlertFlags, E> {
+        let value = self.read_reg(Register::MASK_ENABLE).await?;
+
+        Ok(AlertFlags {
+            alert_function_flag: value & 0b0001_0000 != 0,
+            conversion_ready_flag: value & 0b0000_1000 != 0,
+            math_overflow_flag: value & 0b0000_0100 != 0,
+        })
+    }
+
     /// Delivers the unique chip id
     #[inline(always)]
     pub async fn did(&mut self) -> Result<u16, E> {
-        let mut buffer: [u8; 2] = unsafe { mem::uninitialized() };
-        self.i2c
-            .write_read(self.address, &[Register::DIE_ID.addr()], &mut buffer)
-            .await?;
-
-        Ok((u16(buffer[0]) << 8 | u16(buffer[1])) >> 4)
+        Ok(self.read_reg(Register::DIE_ID).await? >> 4)
     }
 
     /// Delivers the die revision id
     #[inline(always)]
     pub async fn rid(&mut self) -> Result<u16, E> {
-        let mut buffer: [u8; 2] = unsafe { mem::uninitialized() };
-        self.i2c
-            .write_read(self.address, &[Register::DIE_ID.addr()], &mut buffer)
-            .await?;
-
-        Ok(u16(buffer[1]) & 0b1111)
+        Ok(self.read_reg(Register::DIE_ID).await? & 0b1111)
     }
 
     /// Delivers the measured raw current in 1.25mA per bit
     #[inline(always)]
     pub async fn current_raw(&mut self) -> Result<i16, E> {
-        let mut buffer: [u8; 2] = unsafe { mem::uninitialized() };
-        self.i2c
-            .write_read(self.address, &[Register::CURRENT.addr()], &mut buffer)
-            .await?;
-
-        Ok((u16(buffer[0]) << 8 | u16(buffer[1])) as i16)
+        Ok(self.read_reg(Register::CURRENT).await? as i16)
     }
 
     /// Delivers the measured current in uA
@@ -323,12 +658,7 @@ where
     /// Delivers the measured raw voltage in 1.25mV per bit
     #[inline(always)]
     pub async fn voltage_raw(&mut self) -> Result<u16, E> {
-        let mut buffer: [u8; 2] = unsafe { mem::uninitialized() };
-        self.i2c
-            .write_read(self.address, &[Register::VOLTAGE.addr()], &mut buffer)
-            .await?;
-
-        Ok(u16(buffer[0]) << 8 | u16(buffer[1]))
+        self.read_reg(Register::VOLTAGE).await
     }
 
     /// Delivers the measured voltage in uV
@@ -350,12 +680,7 @@ where
     /// Delivers the measured power in 10mW per bit
     #[inline(always)]
     pub async fn power_raw(&mut self) -> Result<u16, E> {
-        let mut buffer: [u8; 2] = unsafe { mem::uninitialized() };
-        self.i2c
-            .write_read(self.address, &[Register::POWER.addr()], &mut buffer)
-            .await?;
-
-        Ok(u16(buffer[0]) << 8 | u16(buffer[1]))
+        self.read_reg(Register::POWER).await
     }
 
     /// Delivers the measured raw power in mW
@@ -374,6 +699,102 @@ where
         Ok((full as u8, rest))
     }
 
+    /// Returns whether the Conversion-Ready Flag (CVRF, bit 3 of MASK_ENABLE) is set. Note that
+    /// reading MASK_ENABLE clears CVRF, so each call consumes the flag.
+    #[inline(always)]
+    pub async fn conversion_ready(&mut self) -> Result<bool, E> {
+        let value = self.read_reg(Register::MASK_ENABLE).await?;
+
+        Ok(value & 0b0000_1000 != 0)
+    }
+
+    /// Floor on the poll budget, used when the configured sample interval is very short (e.g.
+    /// AVG1 with the fastest conversion times), so a handful of retries are always allowed.
+    const MIN_POLL_ATTEMPTS: u32 = 100;
+
+    /// Conservative lower bound on how long a single MASK_ENABLE poll transaction takes, in
+    /// microseconds, used to translate `sample_interval_us()` into a retry count.
+    const MIN_POLL_PERIOD_US: u32 = 50;
+
+    /// Safety margin applied to the configured sample interval before it is converted into a
+    /// poll budget, to absorb bus-speed variance and scheduling jitter.
+    const POLL_BUDGET_MARGIN: u32 = 4;
+
+    /// The number of `conversion_ready()` polls `trigger_and_wait` allows before giving up,
+    /// scaled to the currently configured averaging/conversion-time settings (so e.g. AVG1024
+    /// with both channels enabled gets a budget well above its multi-second conversion time)
+    /// plus margin, so a healthy device is never timed out before it can finish converting.
+    fn max_poll_attempts(&self) -> u32 {
+        let budget_us = self.sample_interval_us().saturating_mul(Self::POLL_BUDGET_MARGIN);
+        (budget_us / Self::MIN_POLL_PERIOD_US).max(Self::MIN_POLL_ATTEMPTS)
+    }
+
+    /// Re-arms the currently configured triggered mode and waits for the Conversion-Ready Flag,
+    /// then delivers the freshly converted current in uA. Only meaningful while the operating
+    /// mode is SCT/BVT/SCBVT; returns `Ok(None)` if the flag never latches within the poll
+    /// budget computed from `sample_interval_us()` (e.g. because the device is in `SHUTDOWN` or
+    /// a continuous mode).
+    #[inline(always)]
+    pub async fn trigger_and_read_current(&mut self) -> Result<Option<i32>, E> {
+        if !self.trigger_and_wait().await? {
+            return Ok(None);
+        }
+        self.current().await.map(Some)
+    }
+
+    /// Re-arms the currently configured triggered mode and waits for the Conversion-Ready Flag,
+    /// then delivers the freshly converted voltage in uV. Only meaningful while the operating
+    /// mode is SCT/BVT/SCBVT; returns `Ok(None)` if the flag never latches within the poll
+    /// budget computed from `sample_interval_us()` (e.g. because the device is in `SHUTDOWN` or
+    /// a continuous mode).
+    #[inline(always)]
+    pub async fn trigger_and_read_voltage(&mut self) -> Result<Option<u32>, E> {
+        if !self.trigger_and_wait().await? {
+            return Ok(None);
+        }
+        self.voltage().await.map(Some)
+    }
+
+    /// Re-arms the currently configured triggered mode and waits for the Conversion-Ready Flag,
+    /// then delivers the freshly converted power in mW. Only meaningful while the operating mode
+    /// is SCT/BVT/SCBVT; returns `Ok(None)` if the flag never latches within the poll budget
+    /// computed from `sample_interval_us()` (e.g. because the device is in `SHUTDOWN` or a
+    /// continuous mode).
+    #[inline(always)]
+    pub async fn trigger_and_read_power(&mut self) -> Result<Option<u32>, E> {
+        if !self.trigger_and_wait().await? {
+            return Ok(None);
+        }
+        self.power().await.map(Some)
+    }
+
+    /// Re-arms the triggered mode and polls for the Conversion-Ready Flag, up to
+    /// `max_poll_attempts()` times. Returns `Ok(false)` on exhaustion instead of looping forever.
+    async fn trigger_and_wait(&mut self) -> Result<bool, E> {
+        self.write_reg(Register::CONFIG, self.state).await?;
+        for _ in 0..self.max_poll_attempts() {
+            if self.conversion_ready().await? {
+                return Ok(true);
+            }
+            yield_now().await;
+        }
+        Ok(false)
+    }
+
+    /// Reads a register as a big-endian u16.
+    pub async fn read_reg<R: Into<u8>>(&mut self, reg: R) -> Result<u16, E> {
+        #[cfg(feature = "defmt")]
+        trace!("read_reg");
+        let reg = reg.into();
+        let mut buffer: [u8; 2] = [0; 2];
+        self.i2c.write_read(self.address, &[reg], &mut buffer).await?;
+        let value = u16(buffer[0]) << 8 | u16(buffer[1]);
+        #[cfg(feature = "defmt")]
+        debug!("R @0x{:x}={:x}", reg, value);
+
+        Ok(value)
+    }
+
     async fn write_reg<R: Into<u8>>(&mut self, reg: R, value: u16) -> Result<(), E> {
         #[cfg(feature = "defmt")]
         trace!("write_reg");